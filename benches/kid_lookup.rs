@@ -0,0 +1,27 @@
+use std::collections::hash_map::RandomState as StdRandomState;
+use std::collections::HashMap;
+
+use ahash::RandomState as AHashState;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Mirrors the shape of the crate's internal `kid` -> key map: a small number
+// of short string keys, looked up on every token validation. Compares the
+// standard library's default hasher against `ahash`, the hasher used when the
+// `fast-hash` feature is enabled.
+fn build_map<S: std::hash::BuildHasher + Default>(len: usize) -> HashMap<String, u64, S> {
+    (0..len).map(|i| (format!("kid-{i}"), i as u64)).collect()
+}
+
+fn bench_kid_lookup(c: &mut Criterion) {
+    let std_map: HashMap<String, u64, StdRandomState> = build_map(64);
+    let ahash_map: HashMap<String, u64, AHashState> = build_map(64);
+    let lookup_key = "kid-32".to_string();
+
+    let mut group = c.benchmark_group("kid_lookup");
+    group.bench_function("siphash (default)", |b| b.iter(|| std_map.get(black_box(&lookup_key))));
+    group.bench_function("ahash (fast-hash feature)", |b| b.iter(|| ahash_map.get(black_box(&lookup_key))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_kid_lookup);
+criterion_main!(benches);
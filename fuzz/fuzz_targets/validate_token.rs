@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(token) = std::str::from_utf8(data) else { return };
+
+    // Metadata decoding is the pre-verification parsing path exercised before any
+    // network or key lookup; it must never panic on arbitrary input.
+    let _ = jwk_box::decode_token_metadata(token);
+});
@@ -0,0 +1,42 @@
+//! The crate's internal notion of "now". Delegates to `Utc::now()` normally; behind
+//! the `test-util` feature a mock time can be installed instead, so consumer
+//! crates can advance time and assert staleness/expiry behavior deterministically.
+//!
+//! Never enable `test-util` in production builds: an installed mock time affects
+//! every `JwkClient` in the process for as long as it's set.
+
+use chrono::{DateTime, Utc};
+
+#[cfg(feature = "test-util")]
+mod mock {
+    use super::*;
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    const NO_MOCK: i64 = i64::MIN;
+    static MOCK_TIME_MILLIS: AtomicI64 = AtomicI64::new(NO_MOCK);
+
+    pub fn now() -> DateTime<Utc> {
+        match MOCK_TIME_MILLIS.load(Ordering::SeqCst) {
+            NO_MOCK => Utc::now(),
+            millis => DateTime::from_timestamp_millis(millis).unwrap_or_else(Utc::now),
+        }
+    }
+
+    /// Installs a mock time that `now()` returns until [`clear_mock_time`] is called.
+    pub fn set_mock_time(time: DateTime<Utc>) {
+        MOCK_TIME_MILLIS.store(time.timestamp_millis(), Ordering::SeqCst);
+    }
+
+    /// Reverts `now()` to the real system clock.
+    pub fn clear_mock_time() {
+        MOCK_TIME_MILLIS.store(NO_MOCK, Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "test-util")]
+pub use mock::{clear_mock_time, now, set_mock_time};
+
+#[cfg(not(feature = "test-util"))]
+pub fn now() -> DateTime<Utc> {
+    Utc::now()
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -10,4 +11,70 @@ pub enum JwkClientErr {
 
     #[error("Could not parse token: {0}")]
     ParseError(#[from] jwt_simple::Error),
+
+    #[error("token issuer host `{token_host}` does not match JWKS host `{jwks_host}`")]
+    IssuerHostMismatch {
+        token_host: String,
+        jwks_host: String,
+    },
+
+    #[error("could not serialize or parse JWKS: {0}")]
+    SerializationError(#[from] serde_json::Error),
+
+    #[error("unexpected JWKS response content type: {0}")]
+    UnexpectedContentType(String),
+
+    #[error("token length {length} exceeds maximum of {max}")]
+    TokenTooLong { length: usize, max: usize },
+
+    #[error("timed out connecting to JWKS endpoint")]
+    ConnectTimeout,
+
+    #[error("timed out reading JWKS response body")]
+    ReadTimeout,
+
+    #[error("token `azp` mismatch: expected `{expected}`, found {actual}")]
+    AuthorizedPartyMismatch { expected: String, actual: String },
+
+    #[error("token's public key id `{0}` not found in loaded keys")]
+    UnknownKeyId(String),
+
+    #[error("token could not be verified against currently loaded keys; a refresh is required but was skipped for this call")]
+    RefreshRequired,
+
+    #[error("token authenticated {age_secs}s ago exceeds max_auth_age of {max_age_secs}s")]
+    AuthTooOld { age_secs: i64, max_age_secs: i64 },
+
+    #[error("token is missing a required `auth_time` claim")]
+    MissingAuthTime,
+
+    #[error("keys are loaded but none are currently valid yet; the earliest becomes valid at {earliest_nbf}")]
+    NoCurrentlyValidKeys { earliest_nbf: DateTime<Utc> },
+
+    #[error("token alg `{actual}` does not exactly match the JWK's declared alg `{expected}`")]
+    AlgorithmMismatch { expected: String, actual: String },
+
+    #[error("JWKS fetch circuit breaker is open; not attempting a fetch until the cooldown elapses")]
+    CircuitOpen,
+
+    #[error("no token found in the provided carrier")]
+    TokenNotFound,
+
+    #[error("{matching_keys} loaded keys all verified the token; rejecting as ambiguous")]
+    AmbiguousVerification { matching_keys: usize },
+
+    #[error("token's public key id `{0}` is not in the configured allow-list")]
+    KidNotAllowed(String),
+
+    #[error("refreshed key set is approximately {actual} bytes, exceeding the configured max of {max} bytes")]
+    CacheBytesExceeded { actual: usize, max: usize },
+
+    #[error("token claims are nested {depth} levels deep, exceeding the configured max of {max}")]
+    ClaimsTooComplex { depth: usize, max: usize },
+
+    #[error("token audience does not match any configured audience under URL-normalized matching")]
+    AudienceMismatch,
+
+    #[error("token lifetime {lifetime_secs}s (exp - iat) exceeds max_token_lifetime of {max_lifetime_secs}s")]
+    TokenLifetimeTooLong { lifetime_secs: i64, max_lifetime_secs: i64 },
 }
@@ -0,0 +1,139 @@
+//! Optional OpenTelemetry integration, gated behind the `opentelemetry` feature.
+//!
+//! Records a span and updates metrics around each JWKS refresh and token
+//! validation, using whatever global tracer/meter provider a host application
+//! has installed - this module never installs one itself, it only calls into
+//! `opentelemetry::global`. Saves consumers who already standardize on
+//! OpenTelemetry from having to bridge [`crate::JwkClientObserver`] themselves.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use opentelemetry::metrics::{Counter, Histogram, Meter, UpDownCounter};
+use opentelemetry::trace::{Span, Status, Tracer};
+use opentelemetry::{global, KeyValue};
+
+/// Instrumentation scope name shared by this crate's tracer and meter.
+const INSTRUMENTATION_SCOPE: &str = "jwk_box";
+
+/// Attribute key identifying which `JwkClient` (by its `jwks_uri`) a metric
+/// data point belongs to, so metrics from several clients in one process -
+/// e.g. this crate's own leader/follower model, or a service validating
+/// against two IdPs - don't collide into a single, meaningless series.
+const JWKS_URI_ATTRIBUTE: &str = "jwk_box.jwks_uri";
+
+struct Metrics {
+    refresh_duration: Histogram<f64>,
+    validations: Counter<u64>,
+    keys_loaded: UpDownCounter<i64>,
+    // last reported key count per `jwks_uri`, so `keys_loaded` (an
+    // UpDownCounter, the only synchronous up/down instrument in the
+    // `opentelemetry` API this crate targets) can be driven with a delta.
+    previous_key_counts: Mutex<HashMap<String, i64>>,
+}
+
+fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(|| {
+        let meter: Meter = global::meter(INSTRUMENTATION_SCOPE);
+        Metrics {
+            refresh_duration: meter
+                .f64_histogram("jwk_box.jwks.refresh.duration")
+                .with_description("Time spent fetching and parsing a JWKS document")
+                .with_unit("s")
+                .build(),
+            validations: meter
+                .u64_counter("jwk_box.token.validations")
+                .with_description("Token validations performed, labeled by outcome")
+                .build(),
+            keys_loaded: meter
+                .i64_up_down_counter("jwk_box.jwks.keys_loaded")
+                .with_description("Number of keys currently loaded from the JWKS, labeled by jwks_uri")
+                .build(),
+            previous_key_counts: Mutex::new(HashMap::new()),
+        }
+    })
+}
+
+/// Wraps a JWKS refresh in a `jwk_box.refresh_keys` span and records its
+/// duration and outcome, both labeled by `jwks_uri` so metrics from multiple
+/// `JwkClient`s in one process stay distinguishable. `keys_loaded` is the size
+/// of the key set after a successful refresh, used to keep the key-count
+/// metric current.
+pub async fn instrument_refresh<F, Fut>(jwks_uri: &str, refresh: F) -> Result<(), crate::JwkClientErr>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<usize, crate::JwkClientErr>>,
+{
+    let tracer = global::tracer(INSTRUMENTATION_SCOPE);
+    let mut span = tracer.start("jwk_box.refresh_keys");
+    span.set_attribute(KeyValue::new(JWKS_URI_ATTRIBUTE, jwks_uri.to_string()));
+
+    let started_at = std::time::Instant::now();
+    let result = refresh().await;
+    let elapsed = started_at.elapsed();
+
+    let outcome = if result.is_ok() { "success" } else { "failure" };
+    metrics().refresh_duration.record(
+        elapsed.as_secs_f64(),
+        &[KeyValue::new("outcome", outcome), KeyValue::new(JWKS_URI_ATTRIBUTE, jwks_uri.to_string())],
+    );
+
+    match &result {
+        Ok(key_count) => {
+            span.set_attribute(KeyValue::new("jwk_box.keys_loaded", *key_count as i64));
+            let delta = key_count_delta(jwks_uri, *key_count as i64);
+            metrics().keys_loaded.add(delta, &[KeyValue::new(JWKS_URI_ATTRIBUTE, jwks_uri.to_string())]);
+        }
+        Err(err) => {
+            span.set_status(Status::error(err.to_string()));
+        }
+    }
+
+    span.end();
+    result.map(|_| ())
+}
+
+/// Records `new_count` as the latest key count reported for `jwks_uri` and
+/// returns the change since the last call for that same `jwks_uri`, so two
+/// `JwkClient`s pointed at different JWKS endpoints never share a baseline.
+fn key_count_delta(jwks_uri: &str, new_count: i64) -> i64 {
+    let mut previous_key_counts = metrics().previous_key_counts.lock().unwrap();
+    let previous = previous_key_counts.insert(jwks_uri.to_string(), new_count).unwrap_or(0);
+    new_count - previous
+}
+
+/// Records a `jwk_box.validate_token` span for an already-completed token
+/// validation and counts its outcome on the `jwk_box.token.validations`
+/// counter. The span is started and ended after the validation finishes, so it
+/// carries the outcome but not the validation's duration.
+pub fn instrument_validation<T>(result: &Result<T, crate::JwkClientErr>) {
+    let tracer = global::tracer(INSTRUMENTATION_SCOPE);
+    let mut span = tracer.start("jwk_box.validate_token");
+
+    let outcome = if result.is_ok() { "success" } else { "failure" };
+    metrics().validations.add(1, &[KeyValue::new("outcome", outcome)]);
+
+    if let Err(err) = result {
+        span.set_status(Status::error(err.to_string()));
+    }
+
+    span.end();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_count_delta_is_independent_per_jwks_uri() {
+        assert_eq!(key_count_delta("https://a.example/jwks.json", 3), 3);
+        assert_eq!(key_count_delta("https://b.example/jwks.json", 5), 5);
+
+        // A second refresh of `a` reports the change relative to its own last
+        // count (3), not `b`'s (5) - the bug this test guards against.
+        assert_eq!(key_count_delta("https://a.example/jwks.json", 4), 1);
+        assert_eq!(key_count_delta("https://b.example/jwks.json", 2), -3);
+    }
+}
@@ -0,0 +1,51 @@
+//! Serde helpers for JWT claim quirks that aren't covered by `serde`'s own
+//! attributes, for use on fields of a caller's own custom claims type.
+
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes a field that's sometimes a bare string and sometimes an array of
+/// strings into a `Vec<String>`. Most commonly needed for a JWT's `aud` claim,
+/// which different issuers encode either way depending on whether the token has
+/// one or several audiences.
+///
+/// Apply via `#[serde(deserialize_with = "jwk_box::serde::string_or_vec")]` on a
+/// field of your own claims type.
+pub fn string_or_vec<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrVec {
+        String(String),
+        Vec(Vec<String>),
+    }
+
+    Ok(match StringOrVec::deserialize(deserializer)? {
+        StringOrVec::String(value) => vec![value],
+        StringOrVec::Vec(values) => values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Claims {
+        #[serde(deserialize_with = "string_or_vec")]
+        aud: Vec<String>,
+    }
+
+    #[test]
+    fn string_or_vec_accepts_a_bare_string() {
+        let claims: Claims = serde_json::from_str(r#"{"aud": "client-a"}"#).unwrap();
+        assert_eq!(claims.aud, vec!["client-a".to_string()]);
+    }
+
+    #[test]
+    fn string_or_vec_accepts_an_array() {
+        let claims: Claims = serde_json::from_str(r#"{"aud": ["client-a", "client-b"]}"#).unwrap();
+        assert_eq!(claims.aud, vec!["client-a".to_string(), "client-b".to_string()]);
+    }
+}